@@ -1,7 +1,7 @@
 // Add `serde` for JSON serialization
 use clap::{Parser, ValueEnum};
 use colored::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use wgpu::Limits;
 
@@ -12,22 +12,93 @@ enum OutputFormat {
     Markdown,
 }
 
+#[derive(Clone, Debug, ValueEnum)]
+enum BackendArg {
+    Vulkan,
+    Metal,
+    Dx12,
+    Gl,
+    All,
+}
+
+impl BackendArg {
+    fn to_wgpu_backends(&self) -> wgpu::Backends {
+        match self {
+            BackendArg::Vulkan => wgpu::Backends::VULKAN,
+            BackendArg::Metal => wgpu::Backends::METAL,
+            BackendArg::Dx12 => wgpu::Backends::DX12,
+            BackendArg::Gl => wgpu::Backends::GL,
+            BackendArg::All => wgpu::Backends::all(),
+        }
+    }
+}
+
+/// A standard wgpu limits baseline to validate an adapter against.
+#[derive(Clone, Debug, ValueEnum)]
+enum CheckBaseline {
+    Default,
+    DownlevelDefaults,
+    DownlevelWebgl2Defaults,
+}
+
+impl CheckBaseline {
+    fn limits(&self) -> wgpu::Limits {
+        match self {
+            CheckBaseline::Default => wgpu::Limits::default(),
+            CheckBaseline::DownlevelDefaults => wgpu::Limits::downlevel_defaults(),
+            CheckBaseline::DownlevelWebgl2Defaults => wgpu::Limits::downlevel_webgl2_defaults(),
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Output format
     #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
     output: OutputFormat,
+
+    /// Restrict enumeration to a single backend (default: all backends)
+    #[arg(short, long, value_enum)]
+    backend: Option<BackendArg>,
+
+    /// Only report the adapter at this index within the enumerated list
+    #[arg(long)]
+    adapter_index: Option<usize>,
+
+    /// Validate the adapter(s) against a standard limits baseline, exiting non-zero on failure
+    #[arg(long, value_enum, conflicts_with = "check_profile")]
+    check: Option<CheckBaseline>,
+
+    /// Validate the adapter(s) against a JSON or TOML file of required limit minimums and
+    /// features, exiting non-zero on failure
+    #[arg(long, conflicts_with = "check")]
+    check_profile: Option<std::path::PathBuf>,
+
+    /// Diff the adapter(s) against a previously saved `--output json` GpuReport
+    #[arg(long, conflicts_with_all = ["check", "check_profile"])]
+    compare: Option<std::path::PathBuf>,
+
+    /// Create a device and dispatch a trivial compute shader to confirm the adapter is
+    /// genuinely usable, not just advertised; failures exit non-zero (composes with `--check`,
+    /// `--check-profile` and `--compare`)
+    #[arg(long)]
+    probe: bool,
 }
 
-/// A combined struct for easy JSON serialization of all GPU info.
-/// wgpu's `AdapterInfo` and `Limits` derive `Serialize` if the "serde" feature is enabled.
-#[derive(Serialize)]
-struct GpuReport<'a> {
-    adapter_info: &'a wgpu::AdapterInfo,
-    limits: &'a wgpu::Limits,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    notes: Option<&'a str>,
+/// A combined, round-trippable snapshot of all GPU info for one adapter, saved and reloaded via
+/// `--compare` (requires wgpu's "trace" or "replay" feature, not "serde").
+#[derive(Serialize, Deserialize, Clone)]
+struct GpuReport {
+    index: usize,
+    adapter_info: wgpu::AdapterInfo,
+    limits: wgpu::Limits,
+    features: wgpu::Features,
+    downlevel: wgpu::DownlevelCapabilities,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    probe: Option<ProbeResult>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    notes: Option<String>,
 }
 
 trait PrettyFormat {
@@ -57,6 +128,801 @@ impl PrettyFormat for u64 {
     }
 }
 
+/// A user-supplied profile of required limit minimums, loaded via `--check-profile`. Unset
+/// fields are not checked; unknown fields are rejected rather than silently ignored.
+#[derive(Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
+struct RequiredLimits {
+    max_texture_dimension_1d: Option<u32>,
+    max_texture_dimension_2d: Option<u32>,
+    max_texture_dimension_3d: Option<u32>,
+    max_texture_array_layers: Option<u32>,
+    max_bind_groups: Option<u32>,
+    max_bindings_per_bind_group: Option<u32>,
+    max_dynamic_uniform_buffers_per_pipeline_layout: Option<u32>,
+    max_dynamic_storage_buffers_per_pipeline_layout: Option<u32>,
+    max_sampled_textures_per_shader_stage: Option<u32>,
+    max_samplers_per_shader_stage: Option<u32>,
+    max_storage_buffers_per_shader_stage: Option<u32>,
+    max_storage_textures_per_shader_stage: Option<u32>,
+    max_uniform_buffers_per_shader_stage: Option<u32>,
+    max_uniform_buffer_binding_size: Option<u32>,
+    max_storage_buffer_binding_size: Option<u32>,
+    max_buffer_size: Option<u64>,
+    max_vertex_buffers: Option<u32>,
+    max_vertex_attributes: Option<u32>,
+    max_vertex_buffer_array_stride: Option<u32>,
+    max_compute_workgroup_size_x: Option<u32>,
+    max_compute_workgroup_size_y: Option<u32>,
+    max_compute_workgroup_size_z: Option<u32>,
+    max_compute_invocations_per_workgroup: Option<u32>,
+    max_compute_workgroup_storage_size: Option<u32>,
+    max_compute_workgroups_per_dimension: Option<u32>,
+    max_push_constant_size: Option<u32>,
+    max_inter_stage_shader_components: Option<u32>,
+    max_non_sampler_bindings: Option<u32>,
+    min_uniform_buffer_offset_alignment: Option<u32>,
+    min_storage_buffer_offset_alignment: Option<u32>,
+}
+
+impl RequiredLimits {
+    /// The subset of fields that were actually specified, as `(field name, minimum)` pairs.
+    fn as_pairs(&self) -> Vec<(&'static str, u64)> {
+        macro_rules! present {
+            ($($field:ident),* $(,)?) => {
+                vec![$((stringify!($field), self.$field.map(|value| value as u64))),*]
+                    .into_iter()
+                    .filter_map(|(name, value)| value.map(|value| (name, value)))
+                    .collect()
+            };
+        }
+
+        present!(
+            max_texture_dimension_1d,
+            max_texture_dimension_2d,
+            max_texture_dimension_3d,
+            max_texture_array_layers,
+            max_bind_groups,
+            max_bindings_per_bind_group,
+            max_dynamic_uniform_buffers_per_pipeline_layout,
+            max_dynamic_storage_buffers_per_pipeline_layout,
+            max_sampled_textures_per_shader_stage,
+            max_samplers_per_shader_stage,
+            max_storage_buffers_per_shader_stage,
+            max_storage_textures_per_shader_stage,
+            max_uniform_buffers_per_shader_stage,
+            max_uniform_buffer_binding_size,
+            max_storage_buffer_binding_size,
+            max_buffer_size,
+            max_vertex_buffers,
+            max_vertex_attributes,
+            max_vertex_buffer_array_stride,
+            max_compute_workgroup_size_x,
+            max_compute_workgroup_size_y,
+            max_compute_workgroup_size_z,
+            max_compute_invocations_per_workgroup,
+            max_compute_workgroup_storage_size,
+            max_compute_workgroups_per_dimension,
+            max_push_constant_size,
+            max_inter_stage_shader_components,
+            max_non_sampler_bindings,
+            min_uniform_buffer_offset_alignment,
+            min_storage_buffer_offset_alignment,
+        )
+    }
+}
+
+/// A user-supplied check profile: required limit minimums plus required feature names.
+#[derive(Deserialize, Default, Clone)]
+#[serde(deny_unknown_fields)]
+struct RequiredProfile {
+    #[serde(default)]
+    limits: RequiredLimits,
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+/// One field of `wgpu::Limits` that failed its required constraint.
+#[derive(Serialize)]
+struct LimitShortfall {
+    field: &'static str,
+    required: u64,
+    actual: u64,
+}
+
+/// The outcome of `--probe`: device creation plus a minimal compute dispatch.
+#[derive(Serialize, Deserialize, Clone)]
+struct ProbeResult {
+    device_created: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    granted_limits: Option<wgpu::Limits>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    dispatch_latency_ms: Option<f64>,
+}
+
+const PROBE_SHADER: &str = r#"
+@group(0) @binding(0)
+var<storage, read_write> output: array<u32>;
+
+@compute @workgroup_size(1)
+fn main() {
+    output[0] = 42u;
+}
+"#;
+
+/// Creates a device and dispatches a trivial compute shader, timing how long that took.
+fn run_probe(adapter: &wgpu::Adapter) -> ProbeResult {
+    let device_and_queue = pollster::block_on(adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: Some("wgpucheck probe device"),
+            required_features: wgpu::Features::empty(),
+            required_limits: adapter.limits(),
+        },
+        None,
+    ));
+
+    let (device, queue) = match device_and_queue {
+        Ok(pair) => pair,
+        Err(err) => {
+            return ProbeResult {
+                device_created: false,
+                error: Some(err.to_string()),
+                granted_limits: None,
+                dispatch_latency_ms: None,
+            }
+        }
+    };
+
+    let granted_limits = device.limits();
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("wgpucheck probe shader"),
+        source: wgpu::ShaderSource::Wgsl(PROBE_SHADER.into()),
+    });
+
+    let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("wgpucheck probe pipeline"),
+        layout: None,
+        module: &shader,
+        entry_point: "main",
+    });
+
+    let output_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("wgpucheck probe output"),
+        size: 4,
+        usage: wgpu::BufferUsages::STORAGE,
+        mapped_at_creation: false,
+    });
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("wgpucheck probe bind group"),
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[wgpu::BindGroupEntry {
+            binding: 0,
+            resource: output_buffer.as_entire_binding(),
+        }],
+    });
+
+    let start = std::time::Instant::now();
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("wgpucheck probe encoder"),
+    });
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("wgpucheck probe pass"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups(1, 1, 1);
+    }
+    queue.submit(Some(encoder.finish()));
+    device.poll(wgpu::Maintain::Wait);
+
+    let dispatch_latency_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    ProbeResult {
+        device_created: true,
+        error: None,
+        granted_limits: Some(granted_limits),
+        dispatch_latency_ms: Some(dispatch_latency_ms),
+    }
+}
+
+/// The result of validating a single adapter against a required profile.
+#[derive(Serialize)]
+struct CheckReport {
+    adapter_index: usize,
+    passed: bool,
+    missing_features: Vec<String>,
+    limit_shortfalls: Vec<LimitShortfall>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    probe: Option<ProbeResult>,
+}
+
+/// Whether `field` is an "at most" constraint, where a smaller required value is stricter.
+fn is_at_most_limit(field: &str) -> bool {
+    matches!(
+        field,
+        "min_uniform_buffer_offset_alignment" | "min_storage_buffer_offset_alignment"
+    )
+}
+
+/// The fields of `wgpu::Limits` this tool validates, as `(field name, value)` pairs.
+fn limit_entries(limits: &wgpu::Limits) -> Vec<(&'static str, u64)> {
+    vec![
+        (
+            "max_texture_dimension_1d",
+            limits.max_texture_dimension_1d as u64,
+        ),
+        (
+            "max_texture_dimension_2d",
+            limits.max_texture_dimension_2d as u64,
+        ),
+        (
+            "max_texture_dimension_3d",
+            limits.max_texture_dimension_3d as u64,
+        ),
+        (
+            "max_texture_array_layers",
+            limits.max_texture_array_layers as u64,
+        ),
+        ("max_bind_groups", limits.max_bind_groups as u64),
+        (
+            "max_bindings_per_bind_group",
+            limits.max_bindings_per_bind_group as u64,
+        ),
+        (
+            "max_dynamic_uniform_buffers_per_pipeline_layout",
+            limits.max_dynamic_uniform_buffers_per_pipeline_layout as u64,
+        ),
+        (
+            "max_dynamic_storage_buffers_per_pipeline_layout",
+            limits.max_dynamic_storage_buffers_per_pipeline_layout as u64,
+        ),
+        (
+            "max_sampled_textures_per_shader_stage",
+            limits.max_sampled_textures_per_shader_stage as u64,
+        ),
+        (
+            "max_samplers_per_shader_stage",
+            limits.max_samplers_per_shader_stage as u64,
+        ),
+        (
+            "max_storage_buffers_per_shader_stage",
+            limits.max_storage_buffers_per_shader_stage as u64,
+        ),
+        (
+            "max_storage_textures_per_shader_stage",
+            limits.max_storage_textures_per_shader_stage as u64,
+        ),
+        (
+            "max_uniform_buffers_per_shader_stage",
+            limits.max_uniform_buffers_per_shader_stage as u64,
+        ),
+        (
+            "max_uniform_buffer_binding_size",
+            limits.max_uniform_buffer_binding_size as u64,
+        ),
+        (
+            "max_storage_buffer_binding_size",
+            limits.max_storage_buffer_binding_size as u64,
+        ),
+        ("max_buffer_size", limits.max_buffer_size),
+        ("max_vertex_buffers", limits.max_vertex_buffers as u64),
+        ("max_vertex_attributes", limits.max_vertex_attributes as u64),
+        (
+            "max_vertex_buffer_array_stride",
+            limits.max_vertex_buffer_array_stride as u64,
+        ),
+        (
+            "max_compute_workgroup_size_x",
+            limits.max_compute_workgroup_size_x as u64,
+        ),
+        (
+            "max_compute_workgroup_size_y",
+            limits.max_compute_workgroup_size_y as u64,
+        ),
+        (
+            "max_compute_workgroup_size_z",
+            limits.max_compute_workgroup_size_z as u64,
+        ),
+        (
+            "max_compute_invocations_per_workgroup",
+            limits.max_compute_invocations_per_workgroup as u64,
+        ),
+        (
+            "max_compute_workgroup_storage_size",
+            limits.max_compute_workgroup_storage_size as u64,
+        ),
+        (
+            "max_compute_workgroups_per_dimension",
+            limits.max_compute_workgroups_per_dimension as u64,
+        ),
+        (
+            "max_push_constant_size",
+            limits.max_push_constant_size as u64,
+        ),
+        (
+            "max_inter_stage_shader_components",
+            limits.max_inter_stage_shader_components as u64,
+        ),
+        (
+            "max_non_sampler_bindings",
+            limits.max_non_sampler_bindings as u64,
+        ),
+        (
+            "min_uniform_buffer_offset_alignment",
+            limits.min_uniform_buffer_offset_alignment as u64,
+        ),
+        (
+            "min_storage_buffer_offset_alignment",
+            limits.min_storage_buffer_offset_alignment as u64,
+        ),
+    ]
+}
+
+fn limit_violated(field: &str, required: u64, actual: u64) -> bool {
+    if is_at_most_limit(field) {
+        actual > required
+    } else {
+        actual < required
+    }
+}
+
+/// Compares every checked field of `actual` against the matching standard baseline.
+fn limit_shortfalls(required: &wgpu::Limits, actual: &wgpu::Limits) -> Vec<LimitShortfall> {
+    limit_entries(required)
+        .into_iter()
+        .zip(limit_entries(actual))
+        .filter_map(|((field, required), (_, actual))| {
+            limit_violated(field, required, actual).then_some(LimitShortfall {
+                field,
+                required,
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Compares only the fields explicitly set in a user-supplied `RequiredLimits` profile.
+fn limit_shortfalls_partial(
+    required: &RequiredLimits,
+    actual: &wgpu::Limits,
+) -> Vec<LimitShortfall> {
+    let actual_entries: std::collections::HashMap<&'static str, u64> =
+        limit_entries(actual).into_iter().collect();
+
+    required
+        .as_pairs()
+        .into_iter()
+        .filter_map(|(field, required)| {
+            let actual = actual_entries.get(field).copied().unwrap_or_default();
+            limit_violated(field, required, actual).then_some(LimitShortfall {
+                field,
+                required,
+                actual,
+            })
+        })
+        .collect()
+}
+
+fn parse_required_features(names: &[String]) -> Result<wgpu::Features, Box<dyn std::error::Error>> {
+    let mut required = wgpu::Features::empty();
+    for name in names {
+        let flag = wgpu::Features::from_name(name)
+            .ok_or_else(|| format!("unknown wgpu feature: {name}"))?;
+        required |= flag;
+    }
+    Ok(required)
+}
+
+fn missing_features(required: wgpu::Features, actual: wgpu::Features) -> Vec<String> {
+    required
+        .iter_names()
+        .filter(|(_, flag)| !actual.contains(*flag))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+fn load_profile_file(
+    path: &std::path::Path,
+) -> Result<RequiredProfile, Box<dyn std::error::Error>> {
+    let contents = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => Ok(toml::from_str(&contents)?),
+        _ => Ok(serde_json::from_str(&contents)?),
+    }
+}
+
+fn print_check_table(report: &CheckReport) {
+    let title = format!("WGPU Check [adapter #{}]", report.adapter_index)
+        .bold()
+        .underline();
+    println!("{title}");
+
+    if report.passed {
+        println!("\n{}", "PASSED".green().bold());
+        return;
+    }
+    println!("\n{}", "FAILED".red().bold());
+
+    if !report.missing_features.is_empty() {
+        println!("\n{}", "Missing Features".bold());
+        for feature in &report.missing_features {
+            println!("  {}", feature.red());
+        }
+    }
+
+    if !report.limit_shortfalls.is_empty() {
+        println!("\n{}", "Limit Shortfalls".bold());
+        for shortfall in &report.limit_shortfalls {
+            println!(
+                "  {: <48} required {} actual {}",
+                shortfall.field.cyan(),
+                shortfall.required.to_string().green(),
+                shortfall.actual.to_string().red(),
+            );
+        }
+    }
+
+    if let Some(probe) = &report.probe {
+        if !probe.device_created {
+            println!("\n{}", "Probe".bold());
+            println!(
+                "  {}",
+                probe
+                    .error
+                    .as_deref()
+                    .unwrap_or("device creation failed")
+                    .red()
+            );
+        }
+    }
+}
+
+fn print_check_markdown(report: &CheckReport) {
+    println!("## WGPU Check [adapter #{}]\n", report.adapter_index);
+    println!(
+        "**Result:** {}\n",
+        if report.passed { "PASSED" } else { "FAILED" }
+    );
+
+    if !report.missing_features.is_empty() {
+        println!("### Missing Features\n");
+        for feature in &report.missing_features {
+            println!("- `{feature}`");
+        }
+        println!();
+    }
+
+    if !report.limit_shortfalls.is_empty() {
+        println!("### Limit Shortfalls\n");
+        println!("| Field | Required | Actual |");
+        println!("|-------|----------|--------|");
+        for shortfall in &report.limit_shortfalls {
+            println!(
+                "| {} | {} | {} |",
+                shortfall.field, shortfall.required, shortfall.actual
+            );
+        }
+        println!();
+    }
+
+    if let Some(probe) = &report.probe {
+        if !probe.device_created {
+            println!("### Probe\n");
+            println!(
+                "Device creation failed: `{}`\n",
+                probe.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+    }
+}
+
+type AdapterData = (
+    usize,
+    wgpu::AdapterInfo,
+    wgpu::Limits,
+    wgpu::Features,
+    wgpu::DownlevelCapabilities,
+);
+
+/// Validates every selected adapter, prints a `CheckReport` per adapter, and exits non-zero
+/// if any adapter fails.
+fn run_check(
+    adapter_data: &[AdapterData],
+    required_features: wgpu::Features,
+    output: &OutputFormat,
+    probes: &[Option<ProbeResult>],
+    shortfalls_of: impl Fn(&wgpu::Limits) -> Vec<LimitShortfall>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut all_passed = true;
+
+    let reports: Vec<CheckReport> = adapter_data
+        .iter()
+        .zip(probes)
+        .map(|((index, _info, limits, features, _downlevel), probe)| {
+            let limit_shortfalls = shortfalls_of(limits);
+            let missing_features = missing_features(required_features, *features);
+            let probe_ok = probe.as_ref().is_none_or(|p| p.device_created);
+            let passed = limit_shortfalls.is_empty() && missing_features.is_empty() && probe_ok;
+            all_passed &= passed;
+            CheckReport {
+                adapter_index: *index,
+                passed,
+                missing_features,
+                limit_shortfalls,
+                probe: probe.clone(),
+            }
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Table => {
+            for report in &reports {
+                print_check_table(report);
+                println!();
+            }
+        }
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&reports)?),
+        OutputFormat::Markdown => {
+            for report in &reports {
+                print_check_markdown(report);
+            }
+        }
+    }
+
+    if all_passed {
+        Ok(())
+    } else {
+        std::process::exit(1);
+    }
+}
+
+/// Which way a changed field moved, so the diff renderer can color it consistently.
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Direction {
+    Increased,
+    Decreased,
+    Changed,
+}
+
+/// A single field that differs between a baseline `GpuReport` and the live adapter.
+#[derive(Serialize)]
+struct FieldDiff {
+    field: String,
+    baseline: String,
+    current: String,
+    direction: Direction,
+}
+
+/// Everything that changed for one adapter between a baseline report and the live adapter.
+#[derive(Serialize)]
+struct AdapterDiff {
+    adapter_index: usize,
+    changes: Vec<FieldDiff>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    probe: Option<ProbeResult>,
+}
+
+fn diff_adapter_info(baseline: &wgpu::AdapterInfo, current: &wgpu::AdapterInfo) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+    if baseline.name != current.name {
+        diffs.push(FieldDiff {
+            field: "name".to_string(),
+            baseline: baseline.name.clone(),
+            current: current.name.clone(),
+            direction: Direction::Changed,
+        });
+    }
+    if baseline.vendor != current.vendor {
+        diffs.push(FieldDiff {
+            field: "vendor".to_string(),
+            baseline: vendor_to_string(baseline.vendor),
+            current: vendor_to_string(current.vendor),
+            direction: Direction::Changed,
+        });
+    }
+    if baseline.device != current.device {
+        diffs.push(FieldDiff {
+            field: "device".to_string(),
+            baseline: format!("0x{:X}", baseline.device),
+            current: format!("0x{:X}", current.device),
+            direction: Direction::Changed,
+        });
+    }
+    if baseline.driver != current.driver {
+        diffs.push(FieldDiff {
+            field: "driver".to_string(),
+            baseline: baseline.driver.clone(),
+            current: current.driver.clone(),
+            direction: Direction::Changed,
+        });
+    }
+    if baseline.driver_info != current.driver_info {
+        diffs.push(FieldDiff {
+            field: "driver_info".to_string(),
+            baseline: baseline.driver_info.clone(),
+            current: current.driver_info.clone(),
+            direction: Direction::Changed,
+        });
+    }
+    diffs
+}
+
+fn diff_limits(baseline: &wgpu::Limits, current: &wgpu::Limits) -> Vec<FieldDiff> {
+    limit_entries(baseline)
+        .into_iter()
+        .zip(limit_entries(current))
+        .filter_map(|((field, baseline), (_, current))| {
+            if baseline == current {
+                return None;
+            }
+            let direction = if current > baseline {
+                Direction::Increased
+            } else {
+                Direction::Decreased
+            };
+            Some(FieldDiff {
+                field: field.to_string(),
+                baseline: baseline.to_string(),
+                current: current.to_string(),
+                direction,
+            })
+        })
+        .collect()
+}
+
+fn diff_features(baseline: wgpu::Features, current: wgpu::Features) -> Vec<FieldDiff> {
+    wgpu::Features::all()
+        .iter_names()
+        .filter_map(|(name, flag)| {
+            let had = baseline.contains(flag);
+            let has = current.contains(flag);
+            if had == has {
+                return None;
+            }
+            Some(FieldDiff {
+                field: format!("feature:{name}"),
+                baseline: had.to_string(),
+                current: has.to_string(),
+                direction: if has {
+                    Direction::Increased
+                } else {
+                    Direction::Decreased
+                },
+            })
+        })
+        .collect()
+}
+
+fn diff_downlevel(
+    baseline: &wgpu::DownlevelCapabilities,
+    current: &wgpu::DownlevelCapabilities,
+) -> Vec<FieldDiff> {
+    let mut diffs = Vec::new();
+
+    if baseline.shader_model != current.shader_model {
+        diffs.push(FieldDiff {
+            field: "shader_model".to_string(),
+            baseline: format!("{:?}", baseline.shader_model),
+            current: format!("{:?}", current.shader_model),
+            direction: Direction::Changed,
+        });
+    }
+
+    diffs.extend(
+        wgpu::DownlevelFlags::all()
+            .iter_names()
+            .filter_map(|(name, flag)| {
+                let had = baseline.flags.contains(flag);
+                let has = current.flags.contains(flag);
+                if had == has {
+                    return None;
+                }
+                Some(FieldDiff {
+                    field: format!("downlevel:{name}"),
+                    baseline: had.to_string(),
+                    current: has.to_string(),
+                    direction: if has {
+                        Direction::Increased
+                    } else {
+                        Direction::Decreased
+                    },
+                })
+            }),
+    );
+
+    diffs
+}
+
+fn diff_gpu_report(baseline: &GpuReport, current: &GpuReport) -> AdapterDiff {
+    let mut changes = diff_adapter_info(&baseline.adapter_info, &current.adapter_info);
+    changes.extend(diff_limits(&baseline.limits, &current.limits));
+    changes.extend(diff_features(baseline.features, current.features));
+    changes.extend(diff_downlevel(&baseline.downlevel, &current.downlevel));
+    AdapterDiff {
+        adapter_index: current.index,
+        changes,
+        probe: current.probe.clone(),
+    }
+}
+
+fn colorize_direction(value: String, direction: Direction) -> ColoredString {
+    match direction {
+        Direction::Increased => value.green(),
+        Direction::Decreased => value.red(),
+        Direction::Changed => value.yellow(),
+    }
+}
+
+fn print_diff_table(diff: &AdapterDiff) {
+    let title = format!("WGPU Diff [adapter #{}]", diff.adapter_index)
+        .bold()
+        .underline();
+    println!("{title}");
+
+    if diff.changes.is_empty() {
+        println!("\n{}", "No changes".dimmed());
+    } else {
+        for change in &diff.changes {
+            let value = colorize_direction(
+                format!("{} -> {}", change.baseline, change.current),
+                change.direction,
+            );
+            println!("  {: <40} {value}", change.field.cyan().bold());
+        }
+    }
+
+    if let Some(probe) = &diff.probe {
+        if !probe.device_created {
+            println!("\n{}", "Probe".bold());
+            println!(
+                "  {}",
+                probe
+                    .error
+                    .as_deref()
+                    .unwrap_or("device creation failed")
+                    .red()
+            );
+        }
+    }
+}
+
+fn print_diff_markdown(diff: &AdapterDiff) {
+    println!("## WGPU Diff [adapter #{}]\n", diff.adapter_index);
+
+    if diff.changes.is_empty() {
+        println!("No changes.\n");
+    } else {
+        println!("| Field | Baseline | Current |");
+        println!("|-------|----------|---------|");
+        for change in &diff.changes {
+            println!(
+                "| {} | `{}` | `{}` |",
+                change.field, change.baseline, change.current
+            );
+        }
+        println!();
+    }
+
+    if let Some(probe) = &diff.probe {
+        if !probe.device_created {
+            println!("### Probe\n");
+            println!(
+                "Device creation failed: `{}`\n",
+                probe.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+    }
+    println!();
+}
+
 /// Converts a PCI vendor ID to a human-readable name.
 fn vendor_to_string(vendor_id: u32) -> String {
     match vendor_id {
@@ -70,8 +936,17 @@ fn vendor_to_string(vendor_id: u32) -> String {
     }
 }
 
-fn print_table_output(info: &wgpu::AdapterInfo, limits: &Limits) {
-    let title = "WGPU Adapter Info & Device Limits".bold().underline();
+fn print_table_output(
+    index: usize,
+    info: &wgpu::AdapterInfo,
+    limits: &Limits,
+    features: &wgpu::Features,
+    downlevel: &wgpu::DownlevelCapabilities,
+    probe: Option<&ProbeResult>,
+) {
+    let title = format!("WGPU Adapter Info & Device Limits [#{index}]")
+        .bold()
+        .underline();
     println!("{title}");
 
     /// All keys should fit in here...
@@ -251,10 +1126,70 @@ fn print_table_output(info: &wgpu::AdapterInfo, limits: &Limits) {
         "Max Inter-Stage Components:",
         limits.max_inter_stage_shader_components,
     );
+
+    // Features
+    println!("\n{}", "Features".bold());
+    // Feature names are longer than MAX_KEY_LEN, so size this column to the longest one.
+    let feature_key_len = wgpu::Features::all()
+        .iter_names()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(MAX_KEY_LEN);
+    for (name, flag) in wgpu::Features::all().iter_names() {
+        let supported = features.contains(flag);
+        let marker = if supported {
+            "supported".green()
+        } else {
+            "unsupported".red()
+        };
+        println!("{: <feature_key_len$} {marker}", name.cyan().bold());
+    }
+
+    // Downlevel
+    println!("\n{}", "Downlevel".bold());
+    print_row(
+        MAX_KEY_LEN,
+        "Shader Model:",
+        format!("{:?}", downlevel.shader_model),
+    );
+    // Downlevel flag names are longer than MAX_KEY_LEN as well.
+    let downlevel_key_len = wgpu::DownlevelFlags::all()
+        .iter_names()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(MAX_KEY_LEN);
+    for (name, flag) in wgpu::DownlevelFlags::all().iter_names() {
+        let supported = downlevel.flags.contains(flag);
+        let marker = if supported {
+            "supported".green()
+        } else {
+            "unsupported".red()
+        };
+        println!("{: <downlevel_key_len$} {marker}", name.cyan().bold());
+    }
+
+    // Probe
+    if let Some(probe) = probe {
+        println!("\n{}", "Probe".bold());
+        print_row(MAX_KEY_LEN, "Device Created:", probe.device_created);
+        if let Some(error) = &probe.error {
+            print_row(MAX_KEY_LEN, "Error:", error);
+        }
+        if let Some(latency) = probe.dispatch_latency_ms {
+            print_row(MAX_KEY_LEN, "Dispatch Latency:", format!("{latency:.3} ms"));
+        }
+    }
 }
 
-fn print_markdown_output(info: &wgpu::AdapterInfo, limits: &Limits) {
-    println!("## WGPU Adapter Information\n");
+fn print_markdown_output(
+    index: usize,
+    info: &wgpu::AdapterInfo,
+    limits: &Limits,
+    features: &wgpu::Features,
+    downlevel: &wgpu::DownlevelCapabilities,
+    probe: Option<&ProbeResult>,
+) {
+    println!("## WGPU Adapter Information [#{index}]\n");
     println!("| Key | Value |");
     println!("|-----|-------|");
     println!("| Name | `{}` |", info.name);
@@ -264,7 +1199,7 @@ fn print_markdown_output(info: &wgpu::AdapterInfo, limits: &Limits) {
     println!("| Driver | `{}` |", info.driver);
     println!("| Driver Info | `{}` |", info.driver_info);
 
-    println!("## WGPU Device Limits\n");
+    println!("## WGPU Device Limits [#{index}]\n");
 
     fn print_section(section_title: &str, rows: &[(&str, String)]) {
         println!("### {section_title}\n");
@@ -434,6 +1369,34 @@ fn print_markdown_output(info: &wgpu::AdapterInfo, limits: &Limits) {
             ),
         ],
     );
+
+    // Features
+    let feature_rows: Vec<(&str, String)> = wgpu::Features::all()
+        .iter_names()
+        .map(|(name, flag)| (name, features.contains(flag).to_string()))
+        .collect();
+    print_section("Features", &feature_rows);
+
+    // Downlevel
+    let mut downlevel_rows = vec![("Shader Model", format!("{:?}", downlevel.shader_model))];
+    downlevel_rows.extend(
+        wgpu::DownlevelFlags::all()
+            .iter_names()
+            .map(|(name, flag)| (name, downlevel.flags.contains(flag).to_string())),
+    );
+    print_section("Downlevel", &downlevel_rows);
+
+    // Probe
+    if let Some(probe) = probe {
+        let mut probe_rows = vec![("Device Created", probe.device_created.to_string())];
+        if let Some(error) = &probe.error {
+            probe_rows.push(("Error", error.clone()));
+        }
+        if let Some(latency) = probe.dispatch_latency_ms {
+            probe_rows.push(("Dispatch Latency", format!("{latency:.3} ms")));
+        }
+        print_section("Probe", &probe_rows);
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -441,28 +1404,310 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let args = Args::parse();
 
-    let instance = wgpu::Instance::default();
-    let adapter = pollster::block_on(async {
-        instance
-            .request_adapter(&wgpu::RequestAdapterOptions::default())
-            .await
-    })?;
+    let backends = args
+        .backend
+        .as_ref()
+        .map_or(wgpu::Backends::all(), BackendArg::to_wgpu_backends);
+
+    let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends,
+        ..Default::default()
+    });
+
+    let adapters = instance.enumerate_adapters(backends);
+    if adapters.is_empty() {
+        return Err("no adapters found for the selected backend(s)".into());
+    }
+
+    let selected: Vec<(usize, wgpu::Adapter)> = adapters
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| args.adapter_index.is_none_or(|idx| *i == idx))
+        .collect();
+
+    if selected.is_empty() {
+        return Err(format!("no adapter at index {}", args.adapter_index.unwrap()).into());
+    }
+
+    let adapter_data: Vec<AdapterData> = selected
+        .iter()
+        .map(|(index, adapter)| {
+            (
+                *index,
+                adapter.get_info(),
+                adapter.limits(),
+                adapter.features(),
+                adapter.get_downlevel_capabilities(),
+            )
+        })
+        .collect();
+
+    let probes: Vec<Option<ProbeResult>> = if args.probe {
+        selected
+            .iter()
+            .map(|(_, adapter)| Some(run_probe(adapter)))
+            .collect()
+    } else {
+        selected.iter().map(|_| None).collect()
+    };
+
+    if let Some(baseline) = &args.check {
+        let required_limits = baseline.limits();
+        return run_check(
+            &adapter_data,
+            wgpu::Features::empty(),
+            &args.output,
+            &probes,
+            |actual| limit_shortfalls(&required_limits, actual),
+        );
+    }
+
+    if let Some(profile_path) = &args.check_profile {
+        let profile = load_profile_file(profile_path)?;
+        let required_features = parse_required_features(&profile.features)?;
+        return run_check(
+            &adapter_data,
+            required_features,
+            &args.output,
+            &probes,
+            |actual| limit_shortfalls_partial(&profile.limits, actual),
+        );
+    }
+
+    if let Some(compare_path) = &args.compare {
+        let baseline_reports: Vec<GpuReport> =
+            serde_json::from_str(&std::fs::read_to_string(compare_path)?)?;
+
+        let diffs: Vec<AdapterDiff> = adapter_data
+            .iter()
+            .zip(&probes)
+            .filter_map(|((index, info, limits, features, downlevel), probe)| {
+                let baseline = baseline_reports
+                    .iter()
+                    .find(|report| report.index == *index)?;
+                let current = GpuReport {
+                    index: *index,
+                    adapter_info: info.clone(),
+                    limits: limits.clone(),
+                    features: *features,
+                    downlevel: downlevel.clone(),
+                    probe: probe.clone(),
+                    notes: None,
+                };
+                Some(diff_gpu_report(baseline, &current))
+            })
+            .collect();
+
+        match args.output {
+            OutputFormat::Table => {
+                for diff in &diffs {
+                    print_diff_table(diff);
+                    println!();
+                }
+            }
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&diffs)?),
+            OutputFormat::Markdown => {
+                for diff in &diffs {
+                    print_diff_markdown(diff);
+                }
+            }
+        }
+
+        if probes
+            .iter()
+            .any(|probe| matches!(probe, Some(p) if !p.device_created))
+        {
+            std::process::exit(1);
+        }
 
-    let info = adapter.get_info();
-    let limits = adapter.limits();
+        return Ok(());
+    }
 
     match args.output {
-        OutputFormat::Table => print_table_output(&info, &limits),
+        OutputFormat::Table => {
+            for ((index, info, limits, features, downlevel), probe) in
+                adapter_data.iter().zip(&probes)
+            {
+                print_table_output(*index, info, limits, features, downlevel, probe.as_ref());
+                println!();
+            }
+        }
         OutputFormat::Json => {
-            let report = GpuReport {
-                adapter_info: &info,
-                limits: &limits,
-                notes: None,
-            };
-            println!("{}", serde_json::to_string_pretty(&report)?)
+            let reports: Vec<GpuReport> = adapter_data
+                .iter()
+                .zip(&probes)
+                .map(
+                    |((index, info, limits, features, downlevel), probe)| GpuReport {
+                        index: *index,
+                        adapter_info: info.clone(),
+                        limits: limits.clone(),
+                        features: *features,
+                        downlevel: downlevel.clone(),
+                        probe: probe.clone(),
+                        notes: None,
+                    },
+                )
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&reports)?)
+        }
+        OutputFormat::Markdown => {
+            for ((index, info, limits, features, downlevel), probe) in
+                adapter_data.iter().zip(&probes)
+            {
+                print_markdown_output(*index, info, limits, features, downlevel, probe.as_ref());
+            }
         }
-        OutputFormat::Markdown => print_markdown_output(&info, &limits),
+    }
+
+    if probes
+        .iter()
+        .any(|probe| matches!(probe, Some(p) if !p.device_created))
+    {
+        std::process::exit(1);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_limits_rejects_unknown_fields() {
+        let result: Result<RequiredLimits, _> = serde_json::from_str(r#"{"mxa_bind_groups":999}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn required_limits_parses_known_fields() {
+        let parsed: RequiredLimits =
+            serde_json::from_str(r#"{"max_bind_groups":4,"max_buffer_size":99999999}"#).unwrap();
+        assert_eq!(parsed.max_bind_groups, Some(4));
+        assert_eq!(parsed.max_buffer_size, Some(99999999));
+        assert_eq!(parsed.max_texture_dimension_1d, None);
+    }
+
+    #[test]
+    fn required_limits_as_pairs_only_includes_specified_fields() {
+        let limits: RequiredLimits =
+            serde_json::from_str(r#"{"max_bind_groups":4,"max_buffer_size":99999999}"#).unwrap();
+        let pairs = limits.as_pairs();
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs.contains(&("max_bind_groups", 4)));
+        assert!(pairs.contains(&("max_buffer_size", 99999999)));
+    }
+
+    #[test]
+    fn limit_shortfalls_partial_flags_max_buffer_size() {
+        let required: RequiredLimits =
+            serde_json::from_str(r#"{"max_buffer_size":99999999999}"#).unwrap();
+        let actual = wgpu::Limits::default();
+
+        let shortfalls = limit_shortfalls_partial(&required, &actual);
+
+        assert_eq!(shortfalls.len(), 1);
+        assert_eq!(shortfalls[0].field, "max_buffer_size");
+        assert_eq!(shortfalls[0].actual, actual.max_buffer_size);
+    }
+
+    #[test]
+    fn limit_shortfalls_partial_ignores_unspecified_fields() {
+        let required = RequiredLimits::default();
+        let actual = wgpu::Limits::default();
+
+        assert!(limit_shortfalls_partial(&required, &actual).is_empty());
+    }
+
+    #[test]
+    fn limit_shortfalls_respects_at_most_semantics() {
+        let required = wgpu::Limits::default();
+        let mut actual = wgpu::Limits::default();
+        actual.min_uniform_buffer_offset_alignment *= 2;
+
+        let shortfalls = limit_shortfalls(&required, &actual);
+
+        assert!(shortfalls
+            .iter()
+            .any(|s| s.field == "min_uniform_buffer_offset_alignment"));
+    }
+
+    #[test]
+    fn missing_features_reports_only_unmet_requirements() {
+        let required = wgpu::Features::DEPTH_CLIP_CONTROL | wgpu::Features::TEXTURE_BINDING_ARRAY;
+        let actual = wgpu::Features::DEPTH_CLIP_CONTROL;
+
+        let missing = missing_features(required, actual);
+
+        assert_eq!(missing, vec!["TEXTURE_BINDING_ARRAY".to_string()]);
+    }
+
+    #[test]
+    fn parse_required_features_rejects_unknown_names() {
+        let result = parse_required_features(&["not-a-real-feature".to_string()]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_required_features_accepts_known_names() {
+        let required =
+            parse_required_features(&["DEPTH_CLIP_CONTROL".to_string()]).expect("known feature");
+        assert!(required.contains(wgpu::Features::DEPTH_CLIP_CONTROL));
+    }
+
+    #[test]
+    fn diff_adapter_info_flags_identity_changes() {
+        let baseline = wgpu::AdapterInfo {
+            name: "GPU A".to_string(),
+            vendor: 0x10DE,
+            device: 0x1234,
+            device_type: wgpu::DeviceType::DiscreteGpu,
+            driver: "driver".to_string(),
+            driver_info: "info".to_string(),
+            backend: wgpu::Backend::Vulkan,
+        };
+        let current = wgpu::AdapterInfo {
+            name: "GPU B".to_string(),
+            vendor: 0x1002,
+            device: 0x5678,
+            ..baseline.clone()
+        };
+
+        let diffs = diff_adapter_info(&baseline, &current);
+
+        assert!(diffs.iter().any(|d| d.field == "name"));
+        assert!(diffs.iter().any(|d| d.field == "vendor"));
+        assert!(diffs.iter().any(|d| d.field == "device"));
+    }
+
+    #[test]
+    fn diff_limits_reports_direction() {
+        let mut baseline = wgpu::Limits::default();
+        let mut current = wgpu::Limits::default();
+        baseline.max_bind_groups = 4;
+        current.max_bind_groups = 8;
+
+        let diffs = diff_limits(&baseline, &current);
+
+        let diff = diffs
+            .iter()
+            .find(|d| d.field == "max_bind_groups")
+            .expect("max_bind_groups should have changed");
+        assert!(matches!(diff.direction, Direction::Increased));
+    }
+
+    #[test]
+    fn diff_features_reports_added_and_removed() {
+        let baseline = wgpu::Features::DEPTH_CLIP_CONTROL;
+        let current = wgpu::Features::TEXTURE_BINDING_ARRAY;
+
+        let diffs = diff_features(baseline, current);
+
+        assert!(diffs.iter().any(|d| d.field == "feature:DEPTH_CLIP_CONTROL"
+            && matches!(d.direction, Direction::Decreased)));
+        assert!(diffs
+            .iter()
+            .any(|d| d.field == "feature:TEXTURE_BINDING_ARRAY"
+                && matches!(d.direction, Direction::Increased)));
+    }
+}